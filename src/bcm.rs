@@ -0,0 +1,199 @@
+//! A binary-code-modulation (BCM) alternative to the default greyscale
+//! [`RowPlan`][crate::display::RowPlan] compiler.
+//!
+//! The default greyscale scheme programs one secondary-alarm interrupt per
+//! distinct brightness level present in a row, so a busy frame with many
+//! distinct values can generate a lot of interrupts per primary cycle. BCM
+//! instead decomposes each LED's brightness into its binary bit-planes, and
+//! shows bit-plane `k` for `2^k` ticks, so a row needs only [`BITS`]
+//! interrupts regardless of how many distinct brightness values it contains.
+//!
+//! [`BcmFrame`] and [`BcmDisplay`] play the same roles as
+//! [`Frame`][crate::display::Frame] and
+//! [`Display`][crate::display::Display], but drive the scan loop from a
+//! [`BcmRowPlan<BITS>`] instead of the default greyscale
+//! [`RowPlan`][crate::display::RowPlan]. The number of bit-planes is the
+//! const generic parameter `BITS` on `BcmFrame`/`BcmDisplay`; it is not an
+//! associated constant of `Matrix` or `Frame`, since stable Rust doesn't let
+//! an array's length depend on a type's own associated constant.
+
+use crate::control::DisplayControl;
+use crate::display::Matrix;
+use crate::render::Render;
+use crate::timer::DisplayTimer;
+
+/// A per-row lighting plan compiled using binary code modulation.
+///
+/// `BcmRowPlan<BITS>` represents a row's LEDs using `BITS` bit-planes: for
+/// bit position `k`, [`masks`](BcmRowPlan::masks)`[k]` is the column mask of
+/// LEDs whose brightness has bit `k` set. A scan loop shows `masks[0]` for 1
+/// tick, then `masks[1]` for 2 ticks, then `masks[2]` for 4 ticks, and so
+/// on, switching the active column mask (not OR-ing it with the previous
+/// one) at each boundary.
+///
+/// `BITS` must be at most 8, since brightness values are 8 bits wide; this
+/// is checked by [`compile()`](BcmRowPlan::compile).
+pub struct BcmRowPlan<const BITS: usize> {
+    /// The column mask to show during each bit-plane, indexed by bit
+    /// position.
+    pub masks: [u16; BITS],
+}
+
+impl<const BITS: usize> BcmRowPlan<BITS> {
+    /// Compiles the column masks for one matrix row from its columns'
+    /// brightness values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is greater than 8 (brightness values are only 8 bits
+    /// wide).
+    pub fn compile(brightnesses: &[u8]) -> BcmRowPlan<BITS> {
+        assert!(BITS <= 8, "BcmRowPlan supports at most 8 bit-planes");
+        let mut masks = [0u16; BITS];
+        for (col, &brightness) in brightnesses.iter().enumerate() {
+            for (bit, mask) in masks.iter_mut().enumerate() {
+                if brightness & (1 << bit) != 0 {
+                    *mask |= 1 << col;
+                }
+            }
+        }
+        BcmRowPlan { masks }
+    }
+
+    /// Returns the tick, relative to the start of the row's time slice, at
+    /// which bit-plane `bit` ends and the scan should move on to the next
+    /// one.
+    ///
+    /// Bit-plane `k` is shown for `2^k` ticks, so the cumulative boundary
+    /// after bit-plane `bit` is `2^(bit + 1) - 1`.
+    pub fn boundary_tick(bit: usize) -> u16 {
+        (1u16 << (bit + 1)) - 1
+    }
+}
+
+/// A 'compiled' representation of a greyscale image using binary code
+/// modulation, in the form [`BcmDisplay`] needs to drive the LEDs.
+///
+/// This is the BCM equivalent of [`Frame`][crate::display::Frame].
+pub trait BcmFrame<const BITS: usize>: Copy + Clone + Default {
+
+    /// The [`Matrix`] this frame is sized for.
+    type Mtx: Matrix;
+
+    /// Updates the frame to show `source`.
+    fn set<R: Render>(&mut self, source: &R);
+
+    /// Returns the compiled bit-plane schedule for matrix row `row`.
+    fn row_plan(&self, row: usize) -> BcmRowPlan<BITS>;
+
+}
+
+/// Drives a [`DisplayTimer`] and [`DisplayControl`] to show a [`BcmFrame`]
+/// using binary code modulation, instead of the default greyscale scheme.
+pub struct BcmDisplay<F: BcmFrame<BITS>, const BITS: usize> {
+    frame: F,
+    row: usize,
+    plan: BcmRowPlan<BITS>,
+    bit: usize,
+}
+
+impl<F: BcmFrame<BITS>, const BITS: usize> Default for BcmDisplay<F, BITS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: BcmFrame<BITS>, const BITS: usize> BcmDisplay<F, BITS> {
+
+    /// Creates a `BcmDisplay` showing nothing (every LED off).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is 0, or greater than 8.
+    pub fn new() -> BcmDisplay<F, BITS> {
+        assert!(BITS >= 1 && BITS <= 8, "BcmDisplay supports 1 to 8 bit-planes");
+        BcmDisplay {
+            frame: F::default(),
+            row: 0,
+            plan: BcmRowPlan { masks: [0; BITS] },
+            bit: BITS,
+        }
+    }
+
+    /// Replaces the image the display is showing.
+    ///
+    /// Can be called at any time other than while interrupting, or
+    /// interruptable by, [`handle_event()`](BcmDisplay::handle_event).
+    pub fn set_frame(&mut self, frame: &F) {
+        self.frame = *frame;
+    }
+
+    /// Handles a timer interrupt.
+    ///
+    /// Must be called from the interrupt handler for the timer passed to
+    /// [`initialise_timer()`][crate::display::initialise_timer].
+    pub fn handle_event<T: DisplayTimer, C: DisplayControl>(
+        &mut self,
+        timer: &mut T,
+        control: &mut C,
+    ) {
+        if timer.check_primary() {
+            self.row = (self.row + 1) % F::Mtx::MATRIX_ROWS;
+            self.plan = self.frame.row_plan(self.row);
+            self.bit = 0;
+            control.display_row_leds(self.row, self.plan.masks[0]);
+            self.program_next_bit(timer);
+        }
+        if timer.check_secondary() {
+            self.bit += 1;
+            let leds = if self.bit < BITS { self.plan.masks[self.bit] } else { 0 };
+            control.display_row_leds(self.row, leds);
+            self.program_next_bit(timer);
+        }
+    }
+
+    fn program_next_bit<T: DisplayTimer>(&self, timer: &mut T) {
+        if self.bit < BITS {
+            timer.program_secondary(BcmRowPlan::<BITS>::boundary_tick(self.bit));
+            timer.enable_secondary();
+        } else {
+            timer.disable_secondary();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_one_mask_per_bit_plane() {
+        // Column 0 is brightness 0b101 (5), column 1 is brightness 0b010 (2).
+        let plan = BcmRowPlan::<3>::compile(&[5, 2]);
+        assert_eq!(plan.masks[0], 0b01); // bit 0: only column 0
+        assert_eq!(plan.masks[1], 0b10); // bit 1: only column 1
+        assert_eq!(plan.masks[2], 0b01); // bit 2: only column 0
+    }
+
+    #[test]
+    fn compiles_with_the_full_eight_bit_planes() {
+        let plan = BcmRowPlan::<8>::compile(&[0xFF]);
+        for mask in plan.masks {
+            assert_eq!(mask, 0b1);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn compile_panics_for_more_than_eight_bit_planes() {
+        BcmRowPlan::<9>::compile(&[0]);
+    }
+
+    #[test]
+    fn boundary_ticks_double_at_each_bit_plane() {
+        assert_eq!(BcmRowPlan::<8>::boundary_tick(0), 1);
+        assert_eq!(BcmRowPlan::<8>::boundary_tick(1), 3);
+        assert_eq!(BcmRowPlan::<8>::boundary_tick(2), 7);
+        assert_eq!(BcmRowPlan::<8>::boundary_tick(7), 255);
+    }
+}
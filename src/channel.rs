@@ -0,0 +1,137 @@
+//! Multi-channel counterparts to [`Frame`][crate::display::Frame] and
+//! [`Display`][crate::display::Display], for matrices with more than one
+//! brightness channel per LED (for example one channel per colour, for an
+//! RGB matrix).
+//!
+//! [`ChannelFrame`] and [`ChannelDisplay`] drive `CHANNELS` independent
+//! [`RowPlan`]s per matrix row, one per channel, sharing a single
+//! [`DisplayTimer`]: since every channel is scheduled from the same
+//! [`GreyscaleProfile`], the tick at which a given brightness level switches
+//! off is the same for every channel, so one secondary alarm can serve them
+//! all.
+
+use crate::display::{Matrix, RowPlan};
+use crate::greyscale::GreyscaleProfile;
+use crate::render::RenderChannels;
+use crate::timer::DisplayTimer;
+
+/// The interface that [`ChannelDisplay`] needs to light LEDs in a matrix row,
+/// on a particular channel.
+///
+/// This is the multi-channel counterpart to
+/// [`DisplayControl`][crate::control::DisplayControl].
+pub trait ChannelControl<const CHANNELS: usize> {
+
+    /// Lights the LEDs in matrix row `row`, channel `channel`, whose columns
+    /// are set in `leds`.
+    ///
+    /// `leds` has one bit per matrix column, starting from the least
+    /// significant bit. All matrix rows other than `row` should be dark, on
+    /// every channel.
+    fn display_row_leds(&mut self, channel: usize, row: usize, leds: u16);
+
+}
+
+/// A 'compiled' representation of a multi-channel greyscale image, in the
+/// form [`ChannelDisplay`] needs to drive the LEDs.
+///
+/// This is the multi-channel counterpart to
+/// [`Frame`][crate::display::Frame].
+pub trait ChannelFrame<const CHANNELS: usize>: Copy + Clone + Default {
+
+    /// The [`Matrix`] this frame is sized for.
+    type Mtx: Matrix;
+
+    /// The [`GreyscaleProfile`] used to schedule this frame's secondary
+    /// alarm interrupts.
+    type Profile: GreyscaleProfile;
+
+    /// Updates the frame to show `source`.
+    fn set<R: RenderChannels<CHANNELS>>(&mut self, source: &R);
+
+    /// Returns the compiled lighting schedule for channel `channel` of
+    /// matrix row `row`.
+    fn row_plan(&self, channel: usize, row: usize) -> RowPlan;
+
+}
+
+/// Drives a [`DisplayTimer`] and [`ChannelControl`] to show a
+/// [`ChannelFrame`].
+///
+/// This is the multi-channel counterpart to
+/// [`Display`][crate::display::Display].
+pub struct ChannelDisplay<F: ChannelFrame<CHANNELS>, const CHANNELS: usize> {
+    frame: F,
+    row: usize,
+    plans: [RowPlan; CHANNELS],
+    level: usize,
+    lit: [u16; CHANNELS],
+}
+
+impl<F: ChannelFrame<CHANNELS>, const CHANNELS: usize> Default for ChannelDisplay<F, CHANNELS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: ChannelFrame<CHANNELS>, const CHANNELS: usize> ChannelDisplay<F, CHANNELS> {
+
+    /// Creates a `ChannelDisplay` showing nothing (every LED off, on every
+    /// channel).
+    pub fn new() -> ChannelDisplay<F, CHANNELS> {
+        ChannelDisplay {
+            frame: F::default(),
+            row: 0,
+            plans: [RowPlan::default(); CHANNELS],
+            level: F::Profile::LEVELS - 1,
+            lit: [0; CHANNELS],
+        }
+    }
+
+    /// Replaces the image the display is showing.
+    ///
+    /// Can be called at any time other than while interrupting, or
+    /// interruptable by, [`handle_event()`](ChannelDisplay::handle_event).
+    pub fn set_frame(&mut self, frame: &F) {
+        self.frame = *frame;
+    }
+
+    /// Handles a timer interrupt.
+    ///
+    /// Must be called from the interrupt handler for the timer passed to
+    /// [`initialise_timer()`][crate::display::initialise_timer].
+    pub fn handle_event<T: DisplayTimer, C: ChannelControl<CHANNELS>>(
+        &mut self,
+        timer: &mut T,
+        control: &mut C,
+    ) {
+        if timer.check_primary() {
+            self.row = (self.row + 1) % F::Mtx::MATRIX_ROWS;
+            self.level = 1;
+            for channel in 0..CHANNELS {
+                let plan = self.frame.row_plan(channel, self.row);
+                self.lit[channel] = plan.lit_at_start();
+                self.plans[channel] = plan;
+                control.display_row_leds(channel, self.row, self.lit[channel]);
+            }
+            self.program_next_level(timer);
+        }
+        if timer.check_secondary() {
+            for channel in 0..CHANNELS {
+                self.lit[channel] &= !self.plans[channel].switch_off(self.level);
+                control.display_row_leds(channel, self.row, self.lit[channel]);
+            }
+            self.level += 1;
+            self.program_next_level(timer);
+        }
+    }
+
+    fn program_next_level<T: DisplayTimer>(&self, timer: &mut T) {
+        if self.level < F::Profile::LEVELS - 1 {
+            timer.program_secondary(F::Profile::ticks_for_level(self.level));
+            timer.enable_secondary();
+        } else {
+            timer.disable_secondary();
+        }
+    }
+}
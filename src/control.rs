@@ -0,0 +1,22 @@
+//! The interface that [`Display`] needs to control the LEDs.
+//!
+//! [`Display`]: crate::display::Display
+
+/// The interface that [`Display`] needs to light LEDs in a matrix row.
+///
+/// The display only ever has LEDs lit from a single matrix row at a time.
+/// [`Display`] calls [`display_row_leds()`](DisplayControl::display_row_leds)
+/// whenever the lit row changes, and again whenever the set of lit columns
+/// within the current row changes (as greyscale levels are switched off
+/// during the row's time slice).
+///
+/// [`Display`]: crate::display::Display
+pub trait DisplayControl {
+
+    /// Lights the LEDs in matrix row `row` whose columns are set in `leds`.
+    ///
+    /// `leds` has one bit per matrix column, starting from the least
+    /// significant bit. All matrix rows other than `row` should be dark.
+    fn display_row_leds(&mut self, row: usize, leds: u16);
+
+}
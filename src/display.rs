@@ -0,0 +1,210 @@
+//! The row-scanning engine: [`Matrix`], [`Frame`], [`RowPlan`] and
+//! [`Display`] itself.
+
+use crate::control::DisplayControl;
+use crate::greyscale::GreyscaleProfile;
+use crate::render::{Render, BRIGHTNESSES};
+use crate::timer::DisplayTimer;
+
+/// The largest number of matrix columns this crate supports.
+pub const MAX_MATRIX_COLS: usize = 16;
+
+/// Describes a display's matrix wiring, and its correspondence to the
+/// visible arrangement of LEDs.
+///
+/// The matrix and image dimensions need not match: matrix rows and columns
+/// describe how the LEDs are wired up, while image rows and columns
+/// describe the visible arrangement that [`Render`] implementations use.
+pub trait Matrix {
+
+    /// The number of matrix columns (at most [`MAX_MATRIX_COLS`]).
+    const MATRIX_COLS: usize;
+
+    /// The number of matrix rows.
+    const MATRIX_ROWS: usize;
+
+    /// The width, in LEDs, of the visible image.
+    const IMAGE_COLS: usize;
+
+    /// The height, in LEDs, of the visible image.
+    const IMAGE_ROWS: usize;
+
+    /// Returns the image coordinates (as used by [`Render::brightness_at`])
+    /// of the LED wired to matrix column `col` of matrix row `row`.
+    fn image_coordinates(col: usize, row: usize) -> (usize, usize);
+
+}
+
+/// A compiled lighting schedule for a single matrix row.
+///
+/// [`RowPlan::compile()`] builds one of these from a row's brightness
+/// values and a [`GreyscaleProfile`]; [`Display`] uses it to know which
+/// columns to light at the start of the row's time slice, and which columns
+/// to switch off as each greyscale level's tick is reached.
+#[derive(Clone, Copy)]
+pub struct RowPlan {
+    lit_at_start: u16,
+    switch_off: [u16; BRIGHTNESSES],
+}
+
+impl Default for RowPlan {
+    fn default() -> RowPlan {
+        RowPlan {
+            lit_at_start: 0,
+            switch_off: [0; BRIGHTNESSES],
+        }
+    }
+}
+
+impl RowPlan {
+    /// Compiles a row's lighting schedule from its columns' brightness
+    /// values, using `P`'s timing curve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `P::LEVELS` is greater than [`BRIGHTNESSES`] (a profile
+    /// can use fewer brightness levels than the crate's built-in 10-level
+    /// resolution, but not more).
+    pub fn compile<P: GreyscaleProfile>(brightnesses: &[u8]) -> RowPlan {
+        assert!(P::LEVELS <= BRIGHTNESSES);
+        let mut plan = RowPlan::default();
+        for (col, &brightness) in brightnesses.iter().enumerate() {
+            let level = brightness as usize;
+            if level > 0 {
+                plan.lit_at_start |= 1 << col;
+            }
+            if level > 0 && level < P::LEVELS - 1 {
+                plan.switch_off[level] |= 1 << col;
+            }
+        }
+        plan
+    }
+
+    /// The columns to light at the start of the row's time slice.
+    pub(crate) fn lit_at_start(&self) -> u16 {
+        self.lit_at_start
+    }
+
+    /// The columns to switch off once `level`'s tick is reached.
+    pub(crate) fn switch_off(&self, level: usize) -> u16 {
+        self.switch_off[level]
+    }
+}
+
+/// A 'compiled' representation of a greyscale image, in the form
+/// [`Display`] needs to drive the LEDs.
+///
+/// A `Frame` instance is sized for a particular [`Matrix`], and scheduled
+/// using a particular [`GreyscaleProfile`].
+pub trait Frame: Copy + Clone + Default {
+
+    /// The [`Matrix`] this frame is sized for.
+    type Mtx: Matrix;
+
+    /// The [`GreyscaleProfile`] used to schedule this frame's secondary
+    /// alarm interrupts.
+    type Profile: GreyscaleProfile;
+
+    /// Updates the frame to show `source`.
+    fn set<R: Render>(&mut self, source: &R);
+
+    /// Returns the compiled lighting schedule for matrix row `row`.
+    ///
+    /// Typically implemented by calling [`RowPlan::compile::<Self::Profile>()`][RowPlan::compile]
+    /// on the row's stored brightness values.
+    fn row_plan(&self, row: usize) -> RowPlan;
+
+}
+
+/// Drives a [`DisplayTimer`] and [`DisplayControl`] to show a [`Frame`].
+///
+/// There will normally be a single `Display` instance in a program using
+/// this crate.
+pub struct Display<F: Frame> {
+    frame: F,
+    row: usize,
+    plan: RowPlan,
+    level: usize,
+    lit: u16,
+}
+
+impl<F: Frame> Default for Display<F> {
+    fn default() -> Display<F> {
+        Display::new()
+    }
+}
+
+impl<F: Frame> Display<F> {
+
+    /// Creates a `Display` showing nothing (every LED off).
+    pub fn new() -> Display<F> {
+        Display {
+            frame: F::default(),
+            row: 0,
+            plan: RowPlan::default(),
+            level: F::Profile::LEVELS - 1,
+            lit: 0,
+        }
+    }
+
+    /// Replaces the image the display is showing.
+    ///
+    /// Can be called at any time other than while interrupting, or
+    /// interruptable by, [`handle_event()`](Display::handle_event).
+    pub fn set_frame(&mut self, frame: &F) {
+        self.frame = *frame;
+    }
+
+    /// Handles a timer interrupt.
+    ///
+    /// Must be called from the interrupt handler for the timer passed to
+    /// [`initialise_timer()`].
+    pub fn handle_event<T: DisplayTimer, C: DisplayControl>(
+        &mut self,
+        timer: &mut T,
+        control: &mut C,
+    ) {
+        if timer.check_primary() {
+            self.row = (self.row + 1) % F::Mtx::MATRIX_ROWS;
+            self.plan = self.frame.row_plan(self.row);
+            self.level = 1;
+            self.lit = self.plan.lit_at_start();
+            control.display_row_leds(self.row, self.lit);
+            self.program_next_level(timer);
+        }
+        if timer.check_secondary() {
+            self.lit &= !self.plan.switch_off(self.level);
+            control.display_row_leds(self.row, self.lit);
+            self.level += 1;
+            self.program_next_level(timer);
+        }
+    }
+
+    fn program_next_level<T: DisplayTimer>(&mut self, timer: &mut T) {
+        // Skip levels with no LEDs to switch off in this row.
+        while self.level < F::Profile::LEVELS - 1 && self.plan.switch_off(self.level) == 0 {
+            self.level += 1;
+        }
+        if self.level < F::Profile::LEVELS - 1 {
+            timer.program_secondary(F::Profile::ticks_for_level(self.level));
+            timer.enable_secondary();
+        } else {
+            timer.disable_secondary();
+        }
+    }
+
+}
+
+/// Initialises `timer` for use by a [`Display`].
+///
+/// Call this once, before creating your [`Display`].
+pub fn initialise_timer<T: DisplayTimer>(timer: &mut T) {
+    timer.initialise_cycle(375);
+}
+
+/// Initialises `control` for use by a [`Display`].
+///
+/// Call this once, before creating your [`Display`].
+pub fn initialise_control<C: DisplayControl>(control: &mut C) {
+    control.display_row_leds(0, 0);
+}
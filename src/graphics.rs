@@ -0,0 +1,111 @@
+//! An optional [`embedded-graphics`][eg] `DrawTarget` bridge.
+//!
+//! This module is only compiled when the `embedded-graphics` feature is
+//! enabled. It lets you draw into a greyscale buffer using any
+//! `embedded-graphics` primitive (lines, text, shapes, ...) and then feed the
+//! result straight into a [`Frame`] via [`Render`].
+//!
+//! [eg]: https://docs.rs/embedded-graphics/
+
+use core::marker::PhantomData;
+
+use embedded_graphics::{
+    geometry::{OriginDimensions, Size},
+    pixelcolor::Gray8,
+    prelude::*,
+    Pixel,
+};
+
+use crate::display::Matrix;
+use crate::render::{Render, MAX_BRIGHTNESS};
+
+/// A greyscale drawing surface sized for a particular [`Matrix`].
+///
+/// `GraphicsBuffer` owns a flat brightness buffer of `N` bytes, one per LED.
+/// `N` must equal `M::IMAGE_COLS * M::IMAGE_ROWS`; this is checked by an
+/// assertion when a `GraphicsBuffer` is created.
+///
+/// It implements `embedded-graphics`'s [`DrawTarget`], so you can draw into
+/// it with that crate's primitives, and it implements [`Render`], so the
+/// result can be copied straight into a [`Frame`] with [`Frame::set()`].
+///
+/// [`Frame`]: crate::display::Frame
+/// [`Frame::set()`]: crate::display::Frame::set
+///
+/// # Example
+///
+/// ```ignore
+/// // Gray8's luma is 0..=255; it's rescaled onto 0..=MAX_BRIGHTNESS, so use
+/// // 255 (not 9) for a fully bright line.
+/// let mut buffer: GraphicsBuffer<MyMatrix, 25> = GraphicsBuffer::new();
+/// Line::new(Point::new(0, 0), Point::new(4, 4))
+///     .into_styled(PrimitiveStyle::with_stroke(Gray8::new(255), 1))
+///     .draw(&mut buffer)
+///     .unwrap();
+/// frame.set(&buffer);
+/// ```
+pub struct GraphicsBuffer<M: Matrix, const N: usize> {
+    brightnesses: [u8; N],
+    _matrix: PhantomData<M>,
+}
+
+impl<M: Matrix, const N: usize> GraphicsBuffer<M, N> {
+    /// Creates a `GraphicsBuffer` with every LED at brightness 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` isn't `M::IMAGE_COLS * M::IMAGE_ROWS`: at compile time
+    /// when evaluated in a const context, at run time otherwise.
+    pub const fn new() -> Self {
+        assert!(N == M::IMAGE_COLS * M::IMAGE_ROWS);
+        GraphicsBuffer {
+            brightnesses: [0; N],
+            _matrix: PhantomData,
+        }
+    }
+}
+
+impl<M: Matrix, const N: usize> Default for GraphicsBuffer<M, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Matrix, const N: usize> Render for GraphicsBuffer<M, N> {
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        self.brightnesses[y * M::IMAGE_COLS + x]
+    }
+}
+
+impl<M: Matrix, const N: usize> OriginDimensions for GraphicsBuffer<M, N> {
+    fn size(&self) -> Size {
+        Size::new(M::IMAGE_COLS as u32, M::IMAGE_ROWS as u32)
+    }
+}
+
+impl<M: Matrix, const N: usize> DrawTarget for GraphicsBuffer<M, N> {
+    type Color = Gray8;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels {
+            if coord.x < 0 || coord.y < 0 {
+                continue;
+            }
+            let (x, y) = (coord.x as usize, coord.y as usize);
+            if x < M::IMAGE_COLS && y < M::IMAGE_ROWS {
+                self.brightnesses[y * M::IMAGE_COLS + x] = luma_to_brightness(color.luma());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rescales an 8-bit luma value (0..=255) onto this crate's 0..=MAX_BRIGHTNESS
+/// greyscale range.
+fn luma_to_brightness(luma: u8) -> u8 {
+    (luma as u16 * MAX_BRIGHTNESS as u16 / 255) as u8
+}
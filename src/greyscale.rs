@@ -0,0 +1,99 @@
+//! Configurable brightness-to-timing curves.
+//!
+//! [`BRIGHTNESSES`] and [`MAX_BRIGHTNESS`] (and the timing curve a
+//! [`Display`] programs into the secondary alarm to realise them) are fixed
+//! at the values used by the [micro:bit MicroPython port][micropython]. The
+//! [`GreyscaleProfile`] trait lets a [`Display`] be configured with fewer
+//! brightness levels, or a different response curve, instead. A profile
+//! can't have more than [`BRIGHTNESSES`] levels:
+//! [`RowPlan::compile()`][crate::display::RowPlan::compile] panics if it
+//! does.
+//!
+//! [`Display`]: crate::display::Display
+//! [micropython]: https://microbit-micropython.readthedocs.io/
+
+/// Maps brightness levels onto points in the display's 375-tick primary
+/// cycle.
+///
+/// A [`Display`] using this profile lights each LED from the start of its
+/// row's time slice until the tick returned by [`ticks_for_level()`], for
+/// the LED's brightness level, then switches it off.
+///
+/// [`Display`]: crate::display::Display
+/// [`ticks_for_level()`]: GreyscaleProfile::ticks_for_level
+pub trait GreyscaleProfile {
+    /// The number of brightness levels this profile supports (including 0).
+    const LEVELS: usize;
+
+    /// The maximum brightness level this profile supports.
+    const MAX_BRIGHTNESS: u8 = (Self::LEVELS as u8) - 1;
+
+    /// Returns the tick, within the 375-tick primary cycle, at which LEDs
+    /// showing `level` should be switched off.
+    ///
+    /// `level` ranges over `1..LEVELS`; level 0 LEDs aren't lit at all, and
+    /// aren't passed to this function. The values returned for successive
+    /// levels must be strictly increasing, and the value for `LEVELS - 1`
+    /// must be 375 (so the brightest level stays lit for the entire cycle).
+    fn ticks_for_level(level: usize) -> u16;
+}
+
+/// The tick, within the 375-tick primary cycle, at which each brightness
+/// level's LEDs are switched off.
+///
+/// Each level's time slice is approximately 1.9× the slice for the level
+/// below it, matching the [micro:bit MicroPython port][micropython].
+///
+/// [micropython]: https://microbit-micropython.readthedocs.io/
+const MICROBIT_TIMINGS: [u16; MicrobitGreyscaleProfile::LEVELS] =
+    [0, 1, 3, 7, 14, 28, 54, 103, 197, 375];
+
+/// The default [`GreyscaleProfile`]: 10 brightness levels, with the timing
+/// curve used by the [micro:bit MicroPython port][micropython].
+///
+/// This is the single source of truth for [`BRIGHTNESSES`] and
+/// [`MAX_BRIGHTNESS`][crate::render::MAX_BRIGHTNESS], which are defined in
+/// terms of it rather than duplicating its values.
+///
+/// [`BRIGHTNESSES`]: crate::render::BRIGHTNESSES
+/// [micropython]: https://microbit-micropython.readthedocs.io/
+pub struct MicrobitGreyscaleProfile;
+
+impl GreyscaleProfile for MicrobitGreyscaleProfile {
+    const LEVELS: usize = 10;
+
+    fn ticks_for_level(level: usize) -> u16 {
+        MICROBIT_TIMINGS[level]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_max_brightness_from_levels() {
+        assert_eq!(MicrobitGreyscaleProfile::LEVELS, 10);
+        assert_eq!(MicrobitGreyscaleProfile::MAX_BRIGHTNESS, 9);
+    }
+
+    #[test]
+    fn ticks_for_level_reaches_the_full_cycle_at_max_brightness() {
+        assert_eq!(
+            MicrobitGreyscaleProfile::ticks_for_level(
+                MicrobitGreyscaleProfile::LEVELS - 1
+            ),
+            375
+        );
+    }
+
+    #[test]
+    fn ticks_for_level_are_strictly_increasing() {
+        let mut previous = 0;
+        for level in 1..MicrobitGreyscaleProfile::LEVELS {
+            let ticks = MicrobitGreyscaleProfile::ticks_for_level(level);
+            assert!(ticks > previous);
+            previous = ticks;
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Compositing several [`Render`] sources into one.
+//!
+//! [`Layered`] combines a fixed-capacity list of `Render` sources, each at
+//! its own integer offset, by taking the maximum brightness of any layer at
+//! each LED. This is useful for assembling a frame out of independently
+//! moving pieces (for example a game's sprites), since overlapping lit
+//! pixels stay lit.
+
+use crate::render::Render;
+
+/// One layer of a [`Layered`] composite: a `Render` source, its `(cols,
+/// rows)` extent, and the `(dx, dy)` offset (in LED columns and rows) at
+/// which it's displayed.
+#[derive(Clone, Copy)]
+struct Layer<'a> {
+    source: &'a dyn Render,
+    cols: usize,
+    rows: usize,
+    dx: i32,
+    dy: i32,
+}
+
+/// A composite [`Render`] source built from up to `N` layers.
+///
+/// `Layered::brightness_at(x, y)` is the maximum, over all layers, of
+/// `layer.brightness_at(x - dx, y - dy)`; a layer contributes nothing at
+/// coordinates that translate to a position outside its own `0..cols,
+/// 0..rows` extent, so layers smaller than the composite (like sprites on a
+/// larger background) don't get asked for out-of-range coordinates.
+pub struct Layered<'a, const N: usize> {
+    layers: [Option<Layer<'a>>; N],
+    count: usize,
+}
+
+impl<'a, const N: usize> Layered<'a, N> {
+    /// Creates a `Layered` composite with no layers.
+    pub const fn new() -> Layered<'a, N> {
+        Layered {
+            layers: [None; N],
+            count: 0,
+        }
+    }
+
+    /// Adds a layer which is `cols` columns by `rows` rows, displayed `dx`
+    /// columns and `dy` rows from the composite's own origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the composite already holds `N` layers.
+    pub fn push(&mut self, source: &'a dyn Render, cols: usize, rows: usize, dx: i32, dy: i32) {
+        self.layers[self.count] = Some(Layer { source, cols, rows, dx, dy });
+        self.count += 1;
+    }
+}
+
+impl<'a, const N: usize> Default for Layered<'a, N> {
+    fn default() -> Layered<'a, N> {
+        Layered::new()
+    }
+}
+
+impl<'a, const N: usize> Render for Layered<'a, N> {
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        let mut result = 0;
+        for layer in self.layers[..self.count].iter().flatten() {
+            let source_x = x as i32 - layer.dx;
+            let source_y = y as i32 - layer.dy;
+            if source_x >= 0
+                && source_y >= 0
+                && (source_x as usize) < layer.cols
+                && (source_y as usize) < layer.rows
+            {
+                result = result.max(layer.source.brightness_at(source_x as usize, source_y as usize));
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Solid(u8);
+
+    impl Render for Solid {
+        fn brightness_at(&self, _x: usize, _y: usize) -> u8 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn takes_the_maximum_brightness_of_overlapping_layers() {
+        let background = Solid(3);
+        let sprite = Solid(9);
+        let mut layered: Layered<2> = Layered::new();
+        layered.push(&background, 5, 5, 0, 0);
+        layered.push(&sprite, 2, 2, 1, 1);
+
+        assert_eq!(layered.brightness_at(0, 0), 3);
+        assert_eq!(layered.brightness_at(1, 1), 9);
+    }
+
+    #[test]
+    fn a_layer_outside_its_extent_contributes_nothing() {
+        let sprite = Solid(9);
+        let mut layered: Layered<1> = Layered::new();
+        layered.push(&sprite, 2, 2, 3, 3);
+
+        // (0, 0) translates to (-3, -3) in the sprite: out of range.
+        assert_eq!(layered.brightness_at(0, 0), 0);
+        // (3, 3) translates to (0, 0): in range.
+        assert_eq!(layered.brightness_at(3, 3), 9);
+        // (5, 5) translates to (2, 2): out of range (cols/rows are 2).
+        assert_eq!(layered.brightness_at(5, 5), 0);
+    }
+
+    #[test]
+    fn an_empty_composite_is_entirely_dark() {
+        let layered: Layered<1> = Layered::new();
+        assert_eq!(layered.brightness_at(0, 0), 0);
+    }
+}
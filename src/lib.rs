@@ -148,16 +148,82 @@
 //!
 //!
 //! [micropython]: https://microbit-micropython.readthedocs.io/
+//!
+//!
+//! # embedded-graphics support
+//!
+//! With the `embedded-graphics` feature enabled, [`graphics::GraphicsBuffer`]
+//! adapts a [`Matrix`] into an `embedded-graphics` `DrawTarget`, so you can
+//! build a [`Render`] implementation using that crate's drawing primitives
+//! instead of writing one by hand.
+//!
+//!
+//! # Scrolling
+//!
+//! [`scroll::Window`] and [`scroll::Scroller`] let you show a [`Render`]
+//! source wider than the physical matrix, scrolling it across the display
+//! over time.
+//!
+//!
+//! # Greyscale profiles
+//!
+//! [`GreyscaleProfile`] lets a [`Display`] use fewer (but not more than
+//! [`BRIGHTNESSES`]) brightness levels, or a different brightness-to-timing
+//! curve, than the
+//! built-in [`MicrobitGreyscaleProfile`]. `Display` is generic over the
+//! [`Frame::Profile`][Frame] to use, and programs the secondary alarm from
+//! that profile's [`ticks_for_level()`][GreyscaleProfile::ticks_for_level].
+//! [`BRIGHTNESSES`] and [`MAX_BRIGHTNESS`] are derived from
+//! `MicrobitGreyscaleProfile` rather than duplicating its values.
+//!
+//!
+//! # Compositing
+//!
+//! [`Layered`] combines several [`Render`] sources, each at its own offset,
+//! into a single source by taking the maximum brightness at each LED.
+//!
+//!
+//! # Multi-channel displays
+//!
+//! [`RenderChannels`] generalises [`Render`] (which it treats as the
+//! single-channel case) to sources with several brightness channels per LED,
+//! for driving multi-colour matrices. [`ChannelFrame`] and [`ChannelDisplay`]
+//! are the multi-channel counterparts to [`Frame`] and [`Display`]: they
+//! drive one [`RowPlan`] per channel per row, sharing a single
+//! [`DisplayTimer`]'s secondary alarm, since every channel is scheduled from
+//! the same [`GreyscaleProfile`].
+//!
+//!
+//! # Binary code modulation
+//!
+//! [`BcmFrame`] and [`BcmDisplay`] are binary-code-modulation counterparts
+//! to [`Frame`] and [`Display`], trading the perceptual 1.9× brightness
+//! curve for a fixed, predictable number of secondary-alarm interrupts per
+//! row.
 
 
 mod control;
 mod display;
 mod timer;
 mod render;
+mod scroll;
+mod greyscale;
+mod layered;
+mod bcm;
+mod channel;
+#[cfg(feature = "embedded-graphics")]
+mod graphics;
 
 pub use control::DisplayControl;
-pub use display::{RowPlan, Matrix, Frame, Display,
+pub use display::{RowPlan, Matrix, Frame, Display, MAX_MATRIX_COLS,
                   initialise_timer, initialise_control,
 };
 pub use timer::DisplayTimer;
-pub use render::{BRIGHTNESSES, MAX_BRIGHTNESS, Render};
+pub use render::{BRIGHTNESSES, MAX_BRIGHTNESS, Render, RenderChannels};
+pub use scroll::{Window, Scroller};
+pub use greyscale::{GreyscaleProfile, MicrobitGreyscaleProfile};
+pub use layered::Layered;
+pub use bcm::{BcmRowPlan, BcmFrame, BcmDisplay};
+pub use channel::{ChannelControl, ChannelFrame, ChannelDisplay};
+#[cfg(feature = "embedded-graphics")]
+pub use graphics::GraphicsBuffer;
@@ -1,10 +1,16 @@
 //! The interface between images and the display.
 
+use crate::greyscale::{GreyscaleProfile, MicrobitGreyscaleProfile};
+
 /// The number of brightness levels for greyscale images (ie, 10).
-pub const BRIGHTNESSES : usize = 10;
+///
+/// This is [`MicrobitGreyscaleProfile::LEVELS`]; see [`GreyscaleProfile`] for
+/// a way to use a different number of levels (or a different timing curve)
+/// instead.
+pub const BRIGHTNESSES : usize = MicrobitGreyscaleProfile::LEVELS;
 
 /// The maximum brightness level for greyscale images (ie, 9; the minimum is 0).
-pub const MAX_BRIGHTNESS : u8 = (BRIGHTNESSES as u8)-1;
+pub const MAX_BRIGHTNESS : u8 = MicrobitGreyscaleProfile::MAX_BRIGHTNESS;
 
 
 /// A trait providing the information that [`Display`] needs to render an image.
@@ -40,8 +46,6 @@ pub const MAX_BRIGHTNESS : u8 = (BRIGHTNESSES as u8)-1;
 ///     [0, 0, 9, 0, 0],
 /// ]);
 /// ```
-
-
 pub trait Render {
 
     /// Returns the brightness value for a single LED.
@@ -66,3 +70,35 @@ pub trait Render {
 
 }
 
+/// A generalisation of [`Render`] to sources with more than one brightness
+/// channel per LED (for example one channel per colour, for an RGB matrix).
+///
+/// `Render` is the `N == 1` case of this trait: anything implementing
+/// `Render` implements `RenderChannels<1>` automatically, reporting its
+/// single brightness as a one-element array.
+///
+/// [`ChannelFrame`][crate::channel::ChannelFrame] is built from a
+/// `RenderChannels<N>` source, and [`ChannelDisplay`][crate::channel::ChannelDisplay]
+/// drives it to a [`ChannelControl`][crate::channel::ChannelControl]
+/// implementation, programming the same shared secondary alarm for every
+/// channel (since all channels are scheduled from the same
+/// [`GreyscaleProfile`][crate::greyscale::GreyscaleProfile]).
+///
+/// [`Display`]: crate::display::Display
+/// [`Matrix`]: crate::display::Matrix
+pub trait RenderChannels<const N: usize> {
+
+    /// Returns the per-channel brightness values for a single LED.
+    ///
+    /// See [`Render::brightness_at`] for the coordinate system and the
+    /// required range of each value.
+    fn channels_at(&self, x: usize, y: usize) -> [u8; N];
+
+}
+
+impl<T: Render> RenderChannels<1> for T {
+    fn channels_at(&self, x: usize, y: usize) -> [u8; 1] {
+        [self.brightness_at(x, y)]
+    }
+}
+
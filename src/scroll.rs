@@ -0,0 +1,208 @@
+//! Scrolling ("marquee") support built on top of [`Render`].
+//!
+//! [`Window`] turns a logical image wider than the physical matrix into a
+//! [`Render`] of just the columns currently visible. [`Scroller`] drives a
+//! `Window`'s offset on a cadence, to animate text or images scrolling
+//! across the display.
+
+use crate::render::Render;
+
+/// A view onto `source_cols` columns of `source`, `x_offset` columns to the
+/// right of the window's own column 0.
+///
+/// `Window::brightness_at(x, y)` reports `source.brightness_at(x +
+/// x_offset, y)`, except that it returns 0 (rather than asking `source` for
+/// an out-of-range coordinate) whenever `x + x_offset` falls outside
+/// `0..source_cols`. This lets content smoothly enter and exit through the
+/// edges of the window.
+pub struct Window<'a, R: Render> {
+    source: &'a R,
+    source_cols: usize,
+    x_offset: i32,
+}
+
+impl<'a, R: Render> Window<'a, R> {
+    /// Creates a window onto `source`, which is `source_cols` columns wide,
+    /// initially showing `source`'s own column 0 at the window's column 0.
+    pub fn new(source: &'a R, source_cols: usize) -> Window<'a, R> {
+        Window {
+            source,
+            source_cols,
+            x_offset: 0,
+        }
+    }
+
+    /// Sets how far (in columns) `source` has been shifted to the left of
+    /// the window.
+    pub fn set_offset(&mut self, x_offset: i32) {
+        self.x_offset = x_offset;
+    }
+}
+
+impl<'a, R: Render> Render for Window<'a, R> {
+    fn brightness_at(&self, x: usize, y: usize) -> u8 {
+        let source_x = x as i32 + self.x_offset;
+        if source_x < 0 || source_x as usize >= self.source_cols {
+            0
+        } else {
+            self.source.brightness_at(source_x as usize, y)
+        }
+    }
+}
+
+/// Drives a [`Window`] to scroll its source leftwards across the display.
+///
+/// The caller calls [`tick()`](Scroller::tick) from a low-frequency
+/// animation timer of its own; this is separate from (and normally much
+/// slower than) the [`DisplayTimer`] driving the display's refresh.
+///
+/// [`DisplayTimer`]: crate::timer::DisplayTimer
+///
+/// The scroll starts with `source` entirely off the right-hand edge of the
+/// matrix, and is complete once `source` has entirely exited through the
+/// left-hand edge.
+pub struct Scroller<'a, R: Render> {
+    window: Window<'a, R>,
+    ticks_per_step: u32,
+    ticks_since_step: u32,
+    final_offset: i32,
+}
+
+impl<'a, R: Render> Scroller<'a, R> {
+    /// Creates a `Scroller` for `source`, which is `source_cols` columns
+    /// wide, to be displayed on a matrix that's `matrix_cols` columns wide.
+    ///
+    /// `ticks_per_step` is the number of calls to [`tick()`](Scroller::tick)
+    /// the scroller waits for between each one-column step.
+    pub fn new(
+        source: &'a R,
+        source_cols: usize,
+        matrix_cols: usize,
+        ticks_per_step: u32,
+    ) -> Scroller<'a, R> {
+        let mut window = Window::new(source, source_cols);
+        window.set_offset(-(matrix_cols as i32));
+        Scroller {
+            window,
+            ticks_per_step,
+            ticks_since_step: 0,
+            final_offset: source_cols as i32,
+        }
+    }
+
+    /// Advances the scroll by one tick, updating `frame` whenever a step
+    /// occurs.
+    ///
+    /// Returns `true` once the scroll has completed; from that point the
+    /// window shows nothing but brightness 0, and calling `tick()` again has
+    /// no further effect.
+    pub fn tick<F: crate::display::Frame>(&mut self, frame: &mut F) -> bool {
+        if self.window.x_offset >= self.final_offset {
+            return true;
+        }
+        self.ticks_since_step += 1;
+        if self.ticks_since_step >= self.ticks_per_step {
+            self.ticks_since_step = 0;
+            self.window.set_offset(self.window.x_offset + 1);
+            frame.set(&self.window);
+        }
+        self.window.x_offset >= self.final_offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::{Frame, Matrix, RowPlan};
+    use crate::greyscale::MicrobitGreyscaleProfile;
+
+    struct Source(&'static [u8]);
+
+    impl Render for Source {
+        fn brightness_at(&self, x: usize, _y: usize) -> u8 {
+            self.0[x]
+        }
+    }
+
+    #[test]
+    fn window_reports_source_shifted_by_offset() {
+        let source = Source(&[1, 2, 3, 4, 5]);
+        let mut window = Window::new(&source, 5);
+        window.set_offset(2);
+        assert_eq!(window.brightness_at(0, 0), 3);
+        assert_eq!(window.brightness_at(2, 0), 5);
+    }
+
+    #[test]
+    fn window_is_dark_outside_the_source() {
+        let source = Source(&[1, 2, 3, 4, 5]);
+        let mut window = Window::new(&source, 5);
+        window.set_offset(-2);
+        assert_eq!(window.brightness_at(0, 0), 0);
+        assert_eq!(window.brightness_at(1, 0), 0);
+        assert_eq!(window.brightness_at(2, 0), 1);
+
+        window.set_offset(3);
+        assert_eq!(window.brightness_at(2, 0), 0);
+    }
+
+    #[derive(Clone, Copy, Default)]
+    struct TestMatrix;
+
+    impl Matrix for TestMatrix {
+        const MATRIX_COLS: usize = 5;
+        const MATRIX_ROWS: usize = 1;
+        const IMAGE_COLS: usize = 5;
+        const IMAGE_ROWS: usize = 1;
+        fn image_coordinates(col: usize, row: usize) -> (usize, usize) {
+            (col, row)
+        }
+    }
+
+    #[derive(Clone, Copy, Default)]
+    struct TestFrame {
+        row: [u8; 5],
+    }
+
+    impl Frame for TestFrame {
+        type Mtx = TestMatrix;
+        type Profile = MicrobitGreyscaleProfile;
+
+        fn set<R: Render>(&mut self, source: &R) {
+            for (col, brightness) in self.row.iter_mut().enumerate() {
+                *brightness = source.brightness_at(col, 0);
+            }
+        }
+
+        fn row_plan(&self, _row: usize) -> RowPlan {
+            RowPlan::compile::<Self::Profile>(&self.row)
+        }
+    }
+
+    #[test]
+    fn scroller_starts_off_the_right_hand_edge() {
+        let source = Source(&[9, 9, 9]);
+        let scroller = Scroller::new(&source, 3, 5, 1);
+        assert_eq!(scroller.window.x_offset, -5);
+    }
+
+    #[test]
+    fn scroller_completes_once_source_has_fully_exited() {
+        let source = Source(&[9, 9, 9]);
+        let mut scroller = Scroller::new(&source, 3, 5, 1);
+        let mut frame = TestFrame::default();
+
+        // final_offset is source_cols (3); the scroll is complete once
+        // x_offset reaches it, one tick per step here.
+        let mut done = false;
+        for _ in 0..8 {
+            done = scroller.tick(&mut frame);
+        }
+        assert!(done);
+        assert_eq!(scroller.window.x_offset, 3);
+
+        // Calling tick() again has no further effect.
+        assert!(scroller.tick(&mut frame));
+        assert_eq!(scroller.window.x_offset, 3);
+    }
+}
@@ -33,7 +33,6 @@
 /// [dal]: https://lancaster-university.github.io/microbit-docs/
 /// [micropython]: https://microbit-micropython.readthedocs.io/
 /// [`Display`]: crate::display::Display
-
 pub trait DisplayTimer {
 
     /// Initialises the timer.